@@ -0,0 +1,248 @@
+//! Append-only Merkle Mountain Range accumulator used to commit the chain's
+//! transactions to a single root and to prove that a given transaction is
+//! part of that commitment without shipping the whole transaction set.
+//!
+//! This mirrors the accumulator/inclusion-proof split used by Diem/Aptos
+//! (`InMemoryAccumulator` / `TransactionInfoWithProof`): leaves are appended
+//! in insertion order, perfect binary subtrees ("peaks") are merged as they
+//! complete, and the root bags the remaining peaks together.
+
+use sha3::{Digest, Sha3_256};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// One step of an inclusion proof: a sibling hash together with which side
+/// of the parent hash it occupies.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofStep {
+    /// The sibling hash encountered at this step.
+    pub sibling: [u8; 32],
+    /// `true` if `sibling` is the left operand of the parent hash (i.e. the
+    /// hash accumulated so far is the right operand).
+    pub sibling_is_left: bool,
+}
+
+/// An inclusion proof for a single leaf against an accumulator root.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InclusionProof {
+    /// The leaf hash the proof is for.
+    pub leaf: [u8; 32],
+    /// Ordered sibling hashes from the leaf up to the accumulator root:
+    /// first the path through the leaf's peak, then the peak-bagging steps.
+    pub steps: Vec<ProofStep>,
+}
+
+/// An append-only Merkle Mountain Range accumulator.
+#[derive(Clone, Debug, Default)]
+pub struct MerkleAccumulator {
+    /// Every leaf hash, in insertion order.
+    leaves: Vec<[u8; 32]>,
+    /// Index into `leaves` for each known transaction id.
+    index_of: HashMap<Uuid, usize>,
+    /// Roots of perfect binary subtrees, ordered left-to-right, each paired
+    /// with its height (0 = a single leaf).
+    peaks: Vec<([u8; 32], u32)>,
+}
+
+fn hash_pair(left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Bags a non-empty slice of peak hashes into a single root by folding
+/// right-to-left: the rightmost peak seeds the accumulator, then each peak
+/// moving left is combined as `hash(peak || accumulator)`.
+fn bag(peaks: &[[u8; 32]]) -> [u8; 32] {
+    let mut iter = peaks.iter().rev();
+    let mut acc = *iter.next().expect("bag() requires at least one peak");
+    for peak in iter {
+        acc = hash_pair(*peak, acc);
+    }
+    acc
+}
+
+/// Computes the Merkle root of a perfect (power-of-two sized) slice of
+/// leaves, recursively.
+fn subtree_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.len() == 1 {
+        return leaves[0];
+    }
+    let mid = leaves.len() / 2;
+    hash_pair(subtree_root(&leaves[..mid]), subtree_root(&leaves[mid..]))
+}
+
+/// Computes the Merkle root of a perfect slice of leaves while recording the
+/// sibling path to `index` (relative to the slice) along the way.
+fn subtree_path(leaves: &[[u8; 32]], index: usize, steps: &mut Vec<ProofStep>) -> [u8; 32] {
+    if leaves.len() == 1 {
+        return leaves[0];
+    }
+    let mid = leaves.len() / 2;
+    if index < mid {
+        let left = subtree_path(&leaves[..mid], index, steps);
+        let right = subtree_root(&leaves[mid..]);
+        steps.push(ProofStep { sibling: right, sibling_is_left: false });
+        hash_pair(left, right)
+    } else {
+        let left = subtree_root(&leaves[..mid]);
+        let right = subtree_path(&leaves[mid..], index - mid, steps);
+        steps.push(ProofStep { sibling: left, sibling_is_left: true });
+        hash_pair(left, right)
+    }
+}
+
+impl MerkleAccumulator {
+    /// Creates an empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of leaves appended so far.
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Whether the accumulator has no leaves.
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Appends a new leaf, associating it with `id` for later proving.
+    pub fn append(&mut self, id: Uuid, leaf: [u8; 32]) {
+        let index = self.leaves.len();
+        self.leaves.push(leaf);
+        self.index_of.insert(id, index);
+
+        self.peaks.push((leaf, 0));
+        while self.peaks.len() >= 2 {
+            let (right, right_height) = self.peaks[self.peaks.len() - 1];
+            let (left, left_height) = self.peaks[self.peaks.len() - 2];
+            if left_height != right_height {
+                break;
+            }
+            self.peaks.truncate(self.peaks.len() - 2);
+            self.peaks.push((hash_pair(left, right), left_height + 1));
+        }
+    }
+
+    /// The accumulator root, or `None` if no leaves have been appended.
+    pub fn root(&self) -> Option<[u8; 32]> {
+        if self.peaks.is_empty() {
+            return None;
+        }
+        let peak_hashes: Vec<[u8; 32]> = self.peaks.iter().map(|(hash, _)| *hash).collect();
+        Some(bag(&peak_hashes))
+    }
+
+    /// Builds an inclusion proof for the transaction `id`, or `None` if it
+    /// has not been appended to this accumulator.
+    pub fn prove(&self, id: &Uuid) -> Option<InclusionProof> {
+        let leaf_index = *self.index_of.get(id)?;
+
+        // Find the peak whose contiguous leaf range contains `leaf_index`.
+        let mut start = 0;
+        let mut peak_position = 0;
+        for (position, (_, height)) in self.peaks.iter().enumerate() {
+            let size = 1usize << height;
+            if leaf_index < start + size {
+                peak_position = position;
+                break;
+            }
+            start += size;
+        }
+        let (_, height) = self.peaks[peak_position];
+        let size = 1usize << height;
+        let subtree_leaves = &self.leaves[start..start + size];
+
+        let mut steps = Vec::new();
+        subtree_path(subtree_leaves, leaf_index - start, &mut steps);
+
+        let peak_hashes: Vec<[u8; 32]> = self.peaks.iter().map(|(hash, _)| *hash).collect();
+        if peak_position + 1 < peak_hashes.len() {
+            let right_partial = bag(&peak_hashes[peak_position + 1..]);
+            steps.push(ProofStep { sibling: right_partial, sibling_is_left: false });
+        }
+        for peak_hash in peak_hashes[..peak_position].iter().rev() {
+            steps.push(ProofStep { sibling: *peak_hash, sibling_is_left: true });
+        }
+
+        Some(InclusionProof { leaf: self.leaves[leaf_index], steps })
+    }
+}
+
+/// Recomputes the root implied by `proof` and checks it against `root`.
+pub fn verify_inclusion(root: [u8; 32], proof: &InclusionProof) -> bool {
+    let mut current = proof.leaf;
+    for step in &proof.steps {
+        current = if step.sibling_is_left {
+            hash_pair(step.sibling, current)
+        } else {
+            hash_pair(current, step.sibling)
+        };
+    }
+    current == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf(byte: u8) -> [u8; 32] {
+        let mut hash = [0u8; 32];
+        hash[0] = byte;
+        hash
+    }
+
+    #[test]
+    fn test_single_leaf_proof() {
+        let mut acc = MerkleAccumulator::new();
+        let id = Uuid::new_v4();
+        acc.append(id, leaf(1));
+
+        let root = acc.root().unwrap();
+        let proof = acc.prove(&id).unwrap();
+        assert!(verify_inclusion(root, &proof));
+    }
+
+    #[test]
+    fn test_non_power_of_two_leaf_count() {
+        let mut acc = MerkleAccumulator::new();
+        let mut ids = Vec::new();
+        for i in 0..5u8 {
+            let id = Uuid::new_v4();
+            acc.append(id, leaf(i));
+            ids.push(id);
+        }
+
+        let root = acc.root().unwrap();
+        for id in &ids {
+            let proof = acc.prove(id).unwrap();
+            assert!(verify_inclusion(root, &proof));
+        }
+    }
+
+    #[test]
+    fn test_tampered_proof_fails() {
+        let mut acc = MerkleAccumulator::new();
+        let mut ids = Vec::new();
+        for i in 0..4u8 {
+            let id = Uuid::new_v4();
+            acc.append(id, leaf(i));
+            ids.push(id);
+        }
+
+        let root = acc.root().unwrap();
+        let mut proof = acc.prove(&ids[2]).unwrap();
+        proof.leaf = leaf(99);
+        assert!(!verify_inclusion(root, &proof));
+    }
+
+    #[test]
+    fn test_unknown_id_has_no_proof() {
+        let mut acc = MerkleAccumulator::new();
+        acc.append(Uuid::new_v4(), leaf(1));
+        assert!(acc.prove(&Uuid::new_v4()).is_none());
+    }
+}
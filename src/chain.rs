@@ -1,13 +1,20 @@
 use crate::{
+    accumulator::{InclusionProof, MerkleAccumulator},
+    confidential,
     error::{Result, LedgerError},
-    types::{Transaction, HardwareAttestation},
+    hardware::{HardwareSecurity, SimulatedHardwareSecurity},
+    types::{
+        ChainExport, EncryptedPayload, MultiQuantumPublicKey, MultiSignature, Transaction,
+        TransactionAuthenticator, TransactionPublicKey,
+    },
 };
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use pqcrypto_dilithium::dilithium2;
 use pqcrypto_traits::sign::{DetachedSignature, PublicKey, SecretKey};
 use log::{info, debug, warn};
+use sha3::{Digest, Sha3_256};
 
 /// Quantum-resistant key pair management
 #[derive(Clone, Debug)]
@@ -17,8 +24,16 @@ struct QuantumKeys {
     secret_key: Vec<u8>,
 }
 
-/// Main chain structure for the ledger
+/// Signing key material backing a chain's `TransactionAuthenticator`: either
+/// a single keypair, or a k-of-n threshold set of keypairs.
 #[derive(Clone, Debug)]
+enum SigningKeys {
+    Single(QuantumKeys),
+    MultiQuantum { keys: Vec<QuantumKeys>, threshold: u8 },
+}
+
+/// Main chain structure for the ledger
+#[derive(Debug)]
 pub struct Chain {
     /// Unique identifier for the chain
     pub id: Uuid,
@@ -28,102 +43,344 @@ pub struct Chain {
     pub created_at: DateTime<Utc>,
     /// Transaction storage
     transactions: HashMap<Uuid, Transaction>,
-    /// Quantum-resistant cryptographic keys
-    quantum_keypair: Option<QuantumKeys>,
+    /// Quantum-resistant signing keys backing this chain's authenticator.
+    /// `None` for a chain imported from an export, which can verify but not sign.
+    signing_keys: Option<SigningKeys>,
+    /// Public key material matching `signing_keys`, always present so a
+    /// verifier who only has the public key (e.g. after `import()`) can
+    /// still verify transactions and the chain as a whole.
+    public_key: TransactionPublicKey,
+    /// Merkle accumulator committing to every transaction in insertion order
+    accumulator: MerkleAccumulator,
+    /// Hardware security backend used to attest to and verify transactions
+    hardware: Box<dyn HardwareSecurity>,
 }
 
 impl Chain {
-    /// Creates a new chain with the given name
+    /// Creates a new single-signer chain with the given name, backed by the
+    /// simulated hardware security module.
     pub fn new(name: String) -> Self {
+        Self::with_hardware_security(name, Box::new(SimulatedHardwareSecurity::new()))
+    }
+
+    /// Creates a new single-signer chain with the given name and hardware
+    /// security backend, e.g. a real TPM 2.0 quote or USB-HID enclave.
+    pub fn with_hardware_security(name: String, hardware: Box<dyn HardwareSecurity>) -> Self {
         info!("Creating new chain: {}", name);
-        
+
         // Generate quantum-resistant keypair
         let (pk, sk) = dilithium2::keypair();
         debug!("Generated quantum-resistant keypair");
-        
+        let public_key = pk.as_bytes().to_vec();
+
         Self {
             id: Uuid::new_v4(),
             name,
             created_at: Utc::now(),
             transactions: HashMap::new(),
-            quantum_keypair: Some(QuantumKeys {
-                public_key: pk.as_bytes().to_vec(),
+            signing_keys: Some(SigningKeys::Single(QuantumKeys {
+                public_key: public_key.clone(),
                 secret_key: sk.as_bytes().to_vec(),
-            }),
+            })),
+            public_key: TransactionPublicKey::Single(public_key),
+            accumulator: MerkleAccumulator::new(),
+            hardware,
         }
     }
 
+    /// Creates a new chain requiring a `threshold`-of-`signer_count`
+    /// Dilithium multi-signature on every transaction, e.g. when multiple
+    /// hardware modules must co-sign. Backed by the simulated hardware
+    /// security module.
+    pub fn new_multi_sig(name: String, signer_count: u8, threshold: u8) -> Result<Self> {
+        Self::multi_sig_with_hardware_security(
+            name,
+            signer_count,
+            threshold,
+            Box::new(SimulatedHardwareSecurity::new()),
+        )
+    }
+
+    /// Creates a new threshold multi-signature chain with the given
+    /// hardware security backend.
+    pub fn multi_sig_with_hardware_security(
+        name: String,
+        signer_count: u8,
+        threshold: u8,
+        hardware: Box<dyn HardwareSecurity>,
+    ) -> Result<Self> {
+        if threshold == 0 || threshold > signer_count {
+            return Err(LedgerError::CryptoError(format!(
+                "threshold {} is invalid for {} signers",
+                threshold, signer_count
+            )));
+        }
+
+        info!("Creating new multi-sig chain: {} ({}-of-{})", name, threshold, signer_count);
+
+        let keys: Vec<QuantumKeys> = (0..signer_count)
+            .map(|_| {
+                let (pk, sk) = dilithium2::keypair();
+                QuantumKeys {
+                    public_key: pk.as_bytes().to_vec(),
+                    secret_key: sk.as_bytes().to_vec(),
+                }
+            })
+            .collect();
+        let public_keys = keys.iter().map(|key| key.public_key.clone()).collect();
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            name,
+            created_at: Utc::now(),
+            transactions: HashMap::new(),
+            signing_keys: Some(SigningKeys::MultiQuantum { keys, threshold }),
+            public_key: TransactionPublicKey::MultiQuantum(MultiQuantumPublicKey { keys: public_keys, threshold }),
+            accumulator: MerkleAccumulator::new(),
+            hardware,
+        })
+    }
+
+    /// Re-imports a chain previously produced by [`Chain::export`]. The
+    /// result can verify transactions and the chain as a whole via the
+    /// embedded public key, but cannot sign new transactions since secret
+    /// keys are never exported.
+    pub fn import(export: ChainExport) -> Result<Self> {
+        Self::import_with_hardware_security(export, Box::new(SimulatedHardwareSecurity::new()))
+    }
+
+    /// Re-imports a chain with an explicit hardware security backend, used
+    /// to verify hardware attestations.
+    pub fn import_with_hardware_security(export: ChainExport, hardware: Box<dyn HardwareSecurity>) -> Result<Self> {
+        let mut transactions = HashMap::new();
+        let mut accumulator = MerkleAccumulator::new();
+        let mut ordered = export.transactions;
+        ordered.sort_by_key(|tx| tx.timestamp);
+        for transaction in ordered {
+            accumulator.append(transaction.id, leaf_hash(&transaction));
+            transactions.insert(transaction.id, transaction);
+        }
+
+        let chain = Self {
+            id: export.id,
+            name: export.name,
+            created_at: export.created_at,
+            transactions,
+            signing_keys: None,
+            public_key: export.public_key,
+            accumulator,
+            hardware,
+        };
+
+        if let Some(expected_root) = export.root {
+            if chain.root() != Some(expected_root) {
+                return Err(LedgerError::TransactionError(
+                    "imported accumulator root does not match the recomputed root".to_string(),
+                ));
+            }
+        }
+
+        chain.verify_chain()?;
+
+        Ok(chain)
+    }
+
+    /// Exports the full chain (including the public key and accumulator
+    /// root, but never secret keys) so a verifier can independently
+    /// re-verify it end to end via [`Chain::import`].
+    pub fn export(&self) -> ChainExport {
+        let mut transactions: Vec<Transaction> = self.transactions.values().cloned().collect();
+        transactions.sort_by_key(|tx| tx.timestamp);
+
+        ChainExport {
+            id: self.id,
+            name: self.name.clone(),
+            created_at: self.created_at,
+            public_key: self.public_key.clone(),
+            transactions,
+            root: self.accumulator.root(),
+        }
+    }
+
+    /// Verifies the entire chain end to end: every transaction's signature
+    /// and hardware attestation, that `previous_transaction` pointers form a
+    /// single unbroken sequence with exactly one genesis transaction and no
+    /// dangling references, and that the Merkle accumulator root recomputed
+    /// from the transactions matches the chain's current root.
+    pub fn verify_chain(&self) -> Result<()> {
+        let mut transactions: Vec<&Transaction> = self.transactions.values().collect();
+        if transactions.is_empty() {
+            return Ok(());
+        }
+        transactions.sort_by_key(|tx| tx.timestamp);
+
+        let mut previous_id: Option<Uuid> = None;
+        let mut genesis_count = 0;
+        let mut rebuilt = MerkleAccumulator::new();
+
+        for transaction in &transactions {
+            if !self.verify_transaction(transaction)? {
+                return Err(LedgerError::TransactionError(
+                    format!("invalid transaction: {}", transaction.id),
+                ));
+            }
+
+            match transaction.previous_transaction {
+                None => genesis_count += 1,
+                Some(previous) if Some(previous) == previous_id => {}
+                Some(_) => {
+                    return Err(LedgerError::TransactionError(format!(
+                        "transaction {} does not chain from the prior transaction",
+                        transaction.id,
+                    )));
+                }
+            }
+
+            previous_id = Some(transaction.id);
+            rebuilt.append(transaction.id, leaf_hash(transaction));
+        }
+
+        if genesis_count != 1 {
+            return Err(LedgerError::TransactionError(format!(
+                "chain must have exactly one genesis transaction, found {}",
+                genesis_count,
+            )));
+        }
+
+        if rebuilt.root() != self.accumulator.root() {
+            return Err(LedgerError::TransactionError(
+                "recomputed accumulator root does not match the chain's root".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Adds a new transaction to the chain
     pub fn add_transaction(&mut self, data: Vec<u8>) -> Result<Uuid> {
-        let quantum_keys = self.quantum_keypair.as_ref()
+        let signing_keys = self.signing_keys.as_ref()
             .ok_or_else(|| LedgerError::CryptoError("No quantum keys available".to_string()))?;
 
         debug!("Creating new transaction with {} bytes of data", data.len());
-        
+
         // Create and sign transaction
-        let transaction = self.create_transaction(data, quantum_keys)?;
+        let transaction = self.create_transaction(data, signing_keys, None)?;
         let transaction_id = transaction.id;
-        
-        // Add to chain
+
+        // Add to chain and fold its leaf hash into the Merkle accumulator
+        self.accumulator.append(transaction_id, leaf_hash(&transaction));
         self.transactions.insert(transaction_id, transaction);
         info!("Added transaction: {}", transaction_id);
-        
+
         Ok(transaction_id)
     }
 
-    fn create_transaction(&self, data: Vec<u8>, keys: &QuantumKeys) -> Result<Transaction> {
-        // Sign data using quantum-resistant algorithm
-        let secret_key = dilithium2::SecretKey::from_bytes(&keys.secret_key)
-            .map_err(|e| LedgerError::CryptoError(e.to_string()))?;
-            
-        let signature = dilithium2::detached_sign(&data, &secret_key);
+    /// Adds a new confidential transaction: `data` is AEAD-encrypted once and
+    /// the resulting key is wrapped separately for each Kyber public key in
+    /// `recipients`, so only a holder of the matching secret key can recover
+    /// it via [`Chain::decrypt`]. The Dilithium signature still covers the
+    /// ciphertext's digest, so confidentiality and authenticity both hold.
+    pub fn add_encrypted_transaction(&mut self, data: Vec<u8>, recipients: &[Vec<u8>]) -> Result<Uuid> {
+        let signing_keys = self.signing_keys.as_ref()
+            .ok_or_else(|| LedgerError::CryptoError("No quantum keys available".to_string()))?;
 
+        debug!("Creating new confidential transaction for {} recipients", recipients.len());
+
+        let (ciphertext, payload) = confidential::encrypt(&data, recipients)?;
+        let transaction = self.create_transaction(ciphertext, signing_keys, Some(payload))?;
+        let transaction_id = transaction.id;
+
+        self.accumulator.append(transaction_id, leaf_hash(&transaction));
+        self.transactions.insert(transaction_id, transaction);
+        info!("Added confidential transaction: {}", transaction_id);
+
+        Ok(transaction_id)
+    }
+
+    /// Decrypts a confidential transaction's payload using a recipient's
+    /// Kyber secret key.
+    pub fn decrypt(&self, transaction: &Transaction, secret_key: &[u8]) -> Result<Vec<u8>> {
+        let payload = transaction.encrypted_payload.as_ref()
+            .ok_or_else(|| LedgerError::CryptoError("transaction has no encrypted payload".to_string()))?;
+
+        confidential::decrypt(&transaction.data, payload, secret_key)
+    }
+
+    /// Returns the current Merkle accumulator root, or `None` if the chain
+    /// has no transactions yet.
+    pub fn root(&self) -> Option<[u8; 32]> {
+        self.accumulator.root()
+    }
+
+    /// Builds a proof that the transaction `id` is included in the chain's
+    /// current accumulator root.
+    pub fn prove(&self, id: &Uuid) -> Result<InclusionProof> {
+        self.accumulator.prove(id)
+            .ok_or_else(|| LedgerError::TransactionError(format!("transaction not found: {}", id)))
+    }
+
+    fn create_transaction(
+        &self,
+        data: Vec<u8>,
+        keys: &SigningKeys,
+        encrypted_payload: Option<EncryptedPayload>,
+    ) -> Result<Transaction> {
         // Get previous transaction ID
         let previous_transaction = self.transactions.values()
             .max_by_key(|tx| tx.timestamp)
             .map(|tx| tx.id);
 
-        // Generate hardware attestation
-        let hardware_attestation = self.generate_hardware_attestation()?;
+        let id = Uuid::new_v4();
+        let timestamp = Utc::now();
+        let device_id = self.hardware.device_id();
+
+        // Sign the canonical digest, not the raw data, so that id, timestamp,
+        // previous_transaction and the attesting device are all tamper-evident.
+        let digest = signable_digest(&id, &timestamp, previous_transaction, &data, &device_id);
+
+        // Have the hardware backend attest to the digest itself, so the
+        // attestation is meaningful per-transaction rather than a static blob.
+        let hardware_attestation = self.hardware.attest(&digest)?;
+        let authenticator = sign_digest(&digest, keys)?;
 
         Ok(Transaction {
-            id: Uuid::new_v4(),
-            timestamp: Utc::now(),
+            id,
+            timestamp,
             data,
-            signature: signature.as_bytes().to_vec(),
+            authenticator,
             previous_transaction,
             hardware_attestation: Some(hardware_attestation),
+            encrypted_payload,
         })
     }
 
-    /// Verifies a transaction's signature and attestation
+    /// Verifies a transaction's signature and attestation. Only needs the
+    /// chain's public key, so this works on an imported, verifier-only chain.
     pub fn verify_transaction(&self, transaction: &Transaction) -> Result<bool> {
-        let quantum_keys = self.quantum_keypair.as_ref()
-            .ok_or_else(|| LedgerError::CryptoError("No quantum keys available".to_string()))?;
-
         debug!("Verifying transaction: {}", transaction.id);
 
-        // Verify quantum signature
-        let public_key = dilithium2::PublicKey::from_bytes(&quantum_keys.public_key)
-            .map_err(|e| LedgerError::CryptoError(e.to_string()))?;
-            
-        let signature = dilithium2::DetachedSignature::from_bytes(&transaction.signature)
-            .map_err(|e| LedgerError::CryptoError(e.to_string()))?;
-
-        let is_valid = dilithium2::verify_detached_signature(
-            &signature,
+        // Verify the authenticator over the same canonical digest that was signed
+        let device_id = transaction.hardware_attestation.as_ref()
+            .map(|attestation| attestation.device_id.as_str())
+            .unwrap_or("");
+        let digest = signable_digest(
+            &transaction.id,
+            &transaction.timestamp,
+            transaction.previous_transaction,
             &transaction.data,
-            &public_key
-        ).is_ok();
+            device_id,
+        );
+
+        let is_valid = verify_digest(&digest, &transaction.authenticator, &self.public_key)?;
 
         if !is_valid {
             warn!("Invalid signature for transaction: {}", transaction.id);
             return Ok(false);
         }
 
-        // Verify hardware attestation if present
+        // Verify hardware attestation against the same digest, if present
         if let Some(attestation) = &transaction.hardware_attestation {
-            self.verify_hardware_attestation(attestation)?;
+            self.hardware.verify(attestation, &digest)?;
         }
 
         Ok(true)
@@ -146,30 +403,177 @@ impl Chain {
             .collect()
     }
 
-    // Placeholder for hardware security integration
-    fn generate_hardware_attestation(&self) -> Result<HardwareAttestation> {
-        debug!("Generating hardware attestation (placeholder)");
-        // In a real implementation, this would interact with secure hardware
-        Ok(HardwareAttestation {
-            timestamp: Utc::now(),
-            device_id: "SIMULATED-TPM-01".to_string(),
-            attestation_data: vec![0, 1, 2, 3], // Placeholder
-        })
+}
+
+/// Encodes the fields that must be authenticated by a transaction's
+/// signature into a canonical, deterministic, length-prefixed byte string.
+/// Field order and widths are fixed so the encoding is reproducible between
+/// signer and verifier.
+fn encode_signable_fields(
+    id: &Uuid,
+    timestamp: &DateTime<Utc>,
+    previous_transaction: Option<Uuid>,
+    data: &[u8],
+    device_id: &str,
+) -> Vec<u8> {
+    let mut bytes = Vec::new();
+
+    bytes.extend_from_slice(id.as_bytes());
+    bytes.extend_from_slice(&timestamp.timestamp_nanos_opt().unwrap_or(0).to_be_bytes());
+
+    match previous_transaction {
+        Some(prev) => {
+            bytes.push(1);
+            bytes.extend_from_slice(prev.as_bytes());
+        }
+        None => bytes.push(0),
     }
 
-    fn verify_hardware_attestation(&self, attestation: &HardwareAttestation) -> Result<()> {
-        debug!("Verifying hardware attestation for device: {}", attestation.device_id);
-        // In a real implementation, this would verify hardware signatures
-        // and attestation data against the TPM/secure enclave
-        if attestation.device_id.starts_with("SIMULATED-TPM-") {
-            Ok(())
-        } else {
-            warn!("Invalid hardware attestation from device: {}", attestation.device_id);
-            Err(LedgerError::HardwareError("Invalid hardware attestation".to_string()))
+    bytes.extend_from_slice(&Sha3_256::digest(data));
+
+    bytes.extend_from_slice(&(device_id.len() as u32).to_be_bytes());
+    bytes.extend_from_slice(device_id.as_bytes());
+
+    bytes
+}
+
+/// Returns the SHA3-256 digest that is signed and verified over, rather
+/// than `data` directly - this binds `id`, `timestamp`,
+/// `previous_transaction` and the attesting device into the signature, so
+/// none of them can be altered without invalidating it.
+fn signable_digest(
+    id: &Uuid,
+    timestamp: &DateTime<Utc>,
+    previous_transaction: Option<Uuid>,
+    data: &[u8],
+    device_id: &str,
+) -> Vec<u8> {
+    Sha3_256::digest(encode_signable_fields(id, timestamp, previous_transaction, data, device_id)).to_vec()
+}
+
+/// Signs `digest` according to `keys`, producing the matching
+/// `TransactionAuthenticator`: a single signature, or - for a threshold
+/// chain - enough co-signer signatures to meet the threshold.
+fn sign_digest(digest: &[u8], keys: &SigningKeys) -> Result<TransactionAuthenticator> {
+    match keys {
+        SigningKeys::Single(key) => {
+            let secret_key = dilithium2::SecretKey::from_bytes(&key.secret_key)
+                .map_err(|e| LedgerError::CryptoError(e.to_string()))?;
+            let signature = dilithium2::detached_sign(digest, &secret_key);
+            Ok(TransactionAuthenticator::Single(signature.as_bytes().to_vec()))
+        }
+        SigningKeys::MultiQuantum { keys, threshold } => {
+            let mut signatures = Vec::new();
+            for (index, key) in keys.iter().enumerate().take(*threshold as usize) {
+                let secret_key = dilithium2::SecretKey::from_bytes(&key.secret_key)
+                    .map_err(|e| LedgerError::CryptoError(e.to_string()))?;
+                let signature = dilithium2::detached_sign(digest, &secret_key);
+                signatures.push((index as u8, signature.as_bytes().to_vec()));
+            }
+            Ok(TransactionAuthenticator::MultiQuantum(MultiSignature { signatures }))
+        }
+    }
+}
+
+/// Verifies `authenticator` over `digest` according to `public_key`. Needs
+/// only public key material, so a verifier without any secret keys (e.g. an
+/// imported chain) can still verify. For a threshold chain this requires at
+/// least `threshold` distinct, valid signatures from known co-signer
+/// indices, rejecting duplicate indices.
+fn verify_digest(digest: &[u8], authenticator: &TransactionAuthenticator, public_key: &TransactionPublicKey) -> Result<bool> {
+    match (public_key, authenticator) {
+        (TransactionPublicKey::Single(key), TransactionAuthenticator::Single(signature_bytes)) => {
+            let public_key = dilithium2::PublicKey::from_bytes(key)
+                .map_err(|e| LedgerError::CryptoError(e.to_string()))?;
+            let signature = dilithium2::DetachedSignature::from_bytes(signature_bytes)
+                .map_err(|e| LedgerError::CryptoError(e.to_string()))?;
+            Ok(dilithium2::verify_detached_signature(&signature, digest, &public_key).is_ok())
+        }
+        (TransactionPublicKey::MultiQuantum(multi_key), TransactionAuthenticator::MultiQuantum(multi_signature)) => {
+            let mut seen_indices = HashSet::new();
+            let mut valid_count = 0u8;
+
+            for (index, signature_bytes) in &multi_signature.signatures {
+                if !seen_indices.insert(*index) {
+                    warn!("Duplicate co-signer index {} in multi-signature", index);
+                    return Ok(false);
+                }
+
+                let key = match multi_key.keys.get(*index as usize) {
+                    Some(key) => key,
+                    None => {
+                        warn!("Unknown co-signer index {} in multi-signature", index);
+                        return Ok(false);
+                    }
+                };
+
+                let public_key = dilithium2::PublicKey::from_bytes(key)
+                    .map_err(|e| LedgerError::CryptoError(e.to_string()))?;
+
+                let signature = match dilithium2::DetachedSignature::from_bytes(signature_bytes) {
+                    Ok(signature) => signature,
+                    Err(_) => continue,
+                };
+
+                if dilithium2::verify_detached_signature(&signature, digest, &public_key).is_ok() {
+                    valid_count += 1;
+                }
+            }
+
+            Ok(valid_count >= multi_key.threshold)
+        }
+        _ => {
+            warn!("Authenticator does not match the chain's public key configuration");
+            Ok(false)
         }
     }
 }
 
+/// Canonical byte encoding of a whole transaction (signable fields plus its
+/// authenticator), used as the pre-image of its Merkle accumulator leaf hash.
+pub(crate) fn canonical_tx_bytes(transaction: &Transaction) -> Vec<u8> {
+    let device_id = transaction.hardware_attestation.as_ref()
+        .map(|attestation| attestation.device_id.as_str())
+        .unwrap_or("");
+
+    let mut bytes = encode_signable_fields(
+        &transaction.id,
+        &transaction.timestamp,
+        transaction.previous_transaction,
+        &transaction.data,
+        device_id,
+    );
+
+    encode_authenticator(&transaction.authenticator, &mut bytes);
+
+    bytes
+}
+
+/// Appends a canonical, length-prefixed encoding of `authenticator` to `bytes`.
+fn encode_authenticator(authenticator: &TransactionAuthenticator, bytes: &mut Vec<u8>) {
+    match authenticator {
+        TransactionAuthenticator::Single(signature) => {
+            bytes.push(0);
+            bytes.extend_from_slice(&(signature.len() as u32).to_be_bytes());
+            bytes.extend_from_slice(signature);
+        }
+        TransactionAuthenticator::MultiQuantum(multi_signature) => {
+            bytes.push(1);
+            bytes.extend_from_slice(&(multi_signature.signatures.len() as u32).to_be_bytes());
+            for (index, signature) in &multi_signature.signatures {
+                bytes.push(*index);
+                bytes.extend_from_slice(&(signature.len() as u32).to_be_bytes());
+                bytes.extend_from_slice(signature);
+            }
+        }
+    }
+}
+
+/// Hashes a transaction into its Merkle accumulator leaf value.
+pub(crate) fn leaf_hash(transaction: &Transaction) -> [u8; 32] {
+    Sha3_256::digest(canonical_tx_bytes(transaction)).into()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -179,7 +583,7 @@ mod tests {
     fn test_chain_creation() {
         let chain = Chain::new("Test Chain".to_string());
         assert_eq!(chain.name, "Test Chain");
-        assert!(chain.quantum_keypair.is_some());
+        assert!(chain.signing_keys.is_some());
     }
 
     #[test]
@@ -241,6 +645,201 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_tampering_invalidates_signature() -> Result<()> {
+        let mut chain = Chain::new("Test Chain".to_string());
+
+        let tx_id = chain.add_transaction(b"original".to_vec())?;
+        let mut tx = chain.get_transaction(&tx_id).unwrap().clone();
+        assert!(chain.verify_transaction(&tx)?);
+
+        // Mutating previous_transaction, timestamp, or id must invalidate the
+        // signature now that they are part of the signed digest.
+        tx.previous_transaction = Some(Uuid::new_v4());
+        assert!(!chain.verify_transaction(&tx)?);
+
+        let mut tx = chain.get_transaction(&tx_id).unwrap().clone();
+        tx.timestamp = tx.timestamp + chrono::Duration::nanoseconds(1);
+        assert!(!chain.verify_transaction(&tx)?);
+
+        let mut tx = chain.get_transaction(&tx_id).unwrap().clone();
+        tx.id = Uuid::new_v4();
+        assert!(!chain.verify_transaction(&tx)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merkle_inclusion_proof() -> Result<()> {
+        use crate::accumulator::verify_inclusion;
+
+        let mut chain = Chain::new("Test Chain".to_string());
+        let tx1_id = chain.add_transaction(b"first".to_vec())?;
+        let tx2_id = chain.add_transaction(b"second".to_vec())?;
+        let tx3_id = chain.add_transaction(b"third".to_vec())?;
+
+        let root = chain.root().unwrap();
+        for id in [tx1_id, tx2_id, tx3_id] {
+            let proof = chain.prove(&id)?;
+            assert!(verify_inclusion(root, &proof));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_hardware_security_backend() -> Result<()> {
+        let mut chain = Chain::with_hardware_security(
+            "Test Chain".to_string(),
+            Box::new(SimulatedHardwareSecurity::new()),
+        );
+
+        let tx_id = chain.add_transaction(b"test data".to_vec())?;
+        let tx = chain.get_transaction(&tx_id).unwrap();
+        assert!(chain.verify_transaction(tx)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_multi_sig_transaction_verifies() -> Result<()> {
+        let mut chain = Chain::new_multi_sig("Test Chain".to_string(), 3, 2)?;
+
+        let tx_id = chain.add_transaction(b"test data".to_vec())?;
+        let tx = chain.get_transaction(&tx_id).unwrap();
+
+        match &tx.authenticator {
+            TransactionAuthenticator::MultiQuantum(multi_signature) => {
+                assert_eq!(multi_signature.signatures.len(), 2);
+            }
+            TransactionAuthenticator::Single(_) => panic!("expected a multi-signature authenticator"),
+        }
+        assert!(chain.verify_transaction(tx)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_multi_sig_rejects_duplicate_co_signer_index() -> Result<()> {
+        let mut chain = Chain::new_multi_sig("Test Chain".to_string(), 3, 2)?;
+
+        let tx_id = chain.add_transaction(b"test data".to_vec())?;
+        let mut tx = chain.get_transaction(&tx_id).unwrap().clone();
+
+        if let TransactionAuthenticator::MultiQuantum(multi_signature) = &mut tx.authenticator {
+            let first = multi_signature.signatures[0].clone();
+            multi_signature.signatures[1] = first;
+        }
+
+        assert!(!chain.verify_transaction(&tx)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_multi_sig_invalid_threshold_rejected() {
+        assert!(Chain::new_multi_sig("Test Chain".to_string(), 3, 0).is_err());
+        assert!(Chain::new_multi_sig("Test Chain".to_string(), 3, 4).is_err());
+    }
+
+    #[test]
+    fn test_confidential_transaction_round_trip() -> Result<()> {
+        use pqcrypto_kyber::kyber1024;
+        use pqcrypto_traits::kem::{PublicKey as KemPublicKey, SecretKey as KemSecretKey};
+
+        let mut chain = Chain::new("Test Chain".to_string());
+        let (recipient_pk, recipient_sk) = kyber1024::keypair();
+
+        let tx_id = chain.add_encrypted_transaction(
+            b"secret payload".to_vec(),
+            &[recipient_pk.as_bytes().to_vec()],
+        )?;
+        let tx = chain.get_transaction(&tx_id).unwrap();
+
+        // The signature must still verify over the ciphertext's digest.
+        assert!(chain.verify_transaction(tx)?);
+        assert_ne!(tx.data, b"secret payload".to_vec());
+
+        let plaintext = chain.decrypt(tx, recipient_sk.as_bytes())?;
+        assert_eq!(plaintext, b"secret payload".to_vec());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_confidential_transaction_wrong_key_fails() -> Result<()> {
+        use pqcrypto_kyber::kyber1024;
+        use pqcrypto_traits::kem::{PublicKey as KemPublicKey, SecretKey as KemSecretKey};
+
+        let mut chain = Chain::new("Test Chain".to_string());
+        let (recipient_pk, _) = kyber1024::keypair();
+        let (_, other_sk) = kyber1024::keypair();
+
+        let tx_id = chain.add_encrypted_transaction(
+            b"secret payload".to_vec(),
+            &[recipient_pk.as_bytes().to_vec()],
+        )?;
+        let tx = chain.get_transaction(&tx_id).unwrap();
+
+        assert!(chain.decrypt(tx, other_sk.as_bytes()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_import_round_trip_verifies() -> Result<()> {
+        let mut chain = Chain::new("Test Chain".to_string());
+        chain.add_transaction(b"first".to_vec())?;
+        chain.add_transaction(b"second".to_vec())?;
+
+        let export = chain.export();
+        let imported = Chain::import(export)?;
+
+        assert_eq!(imported.id, chain.id);
+        assert_eq!(imported.get_all_transactions().len(), 2);
+        assert_eq!(imported.root(), chain.root());
+        imported.verify_chain()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_rejects_tampered_transaction() -> Result<()> {
+        let mut chain = Chain::new("Test Chain".to_string());
+        let tx_id = chain.add_transaction(b"original".to_vec())?;
+
+        let mut export = chain.export();
+        for transaction in export.transactions.iter_mut() {
+            if transaction.id == tx_id {
+                transaction.data = b"tampered".to_vec();
+            }
+        }
+
+        assert!(Chain::import(export).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_chain_rejects_broken_sequence() -> Result<()> {
+        let mut chain = Chain::new("Test Chain".to_string());
+        chain.add_transaction(b"first".to_vec())?;
+        let tx2_id = chain.add_transaction(b"second".to_vec())?;
+
+        let tx2 = chain.transactions.get_mut(&tx2_id).unwrap();
+        tx2.previous_transaction = None;
+
+        assert!(chain.verify_chain().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_chain_verifies_trivially() {
+        let chain = Chain::new("Test Chain".to_string());
+        assert!(chain.verify_chain().is_ok());
+    }
+
     #[test]
     fn test_hardware_attestation() -> Result<()> {
         let mut chain = Chain::new("Test Chain".to_string());
@@ -0,0 +1,174 @@
+//! Confidential transaction payloads.
+//!
+//! Following the encrypted-private-transactions model used by OpenEthereum,
+//! a transaction's `data` can be encrypted for one or more recipients: a
+//! fresh per-transaction symmetric key AEAD-encrypts the payload once, and
+//! that key is then wrapped separately for each recipient using a
+//! post-quantum KEM (Kyber), so only holders of the matching secret key can
+//! recover it.
+
+use crate::{
+    error::{LedgerError, Result},
+    types::{EncryptedPayload, WrappedKey},
+};
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use pqcrypto_kyber::kyber1024;
+use pqcrypto_traits::kem::{Ciphertext, PublicKey, SecretKey, SharedSecret};
+use sha3::{Digest, Sha3_256};
+
+/// Parses a nonce from untrusted bytes, returning a `CryptoError` instead of
+/// panicking when the length doesn't match the AEAD's fixed nonce size.
+fn nonce_from_slice(bytes: &[u8]) -> Result<Nonce> {
+    if bytes.len() != 12 {
+        return Err(LedgerError::CryptoError(format!(
+            "invalid nonce length: expected 12 bytes, got {}",
+            bytes.len()
+        )));
+    }
+    Ok(*Nonce::from_slice(bytes))
+}
+
+/// Encrypts `data` for every recipient in `recipients` (Kyber public keys),
+/// returning the ciphertext to store as `Transaction::data` and the
+/// per-recipient wrapped keys.
+pub(crate) fn encrypt(data: &[u8], recipients: &[Vec<u8>]) -> Result<(Vec<u8>, EncryptedPayload)> {
+    if recipients.is_empty() {
+        return Err(LedgerError::CryptoError("at least one recipient is required".to_string()));
+    }
+
+    let payload_key = ChaCha20Poly1305::generate_key(&mut OsRng);
+    let payload_nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = ChaCha20Poly1305::new(&payload_key)
+        .encrypt(&payload_nonce, data)
+        .map_err(|e| LedgerError::CryptoError(e.to_string()))?;
+
+    let mut wrapped_keys = Vec::with_capacity(recipients.len());
+    for recipient in recipients {
+        let recipient_key = kyber1024::PublicKey::from_bytes(recipient)
+            .map_err(|e| LedgerError::CryptoError(e.to_string()))?;
+        let (shared_secret, kem_ciphertext) = kyber1024::encapsulate(&recipient_key);
+
+        let wrap_key_bytes = Sha3_256::digest(shared_secret.as_bytes());
+        let wrap_key = Key::from_slice(&wrap_key_bytes);
+        let wrap_nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let wrapped_key = ChaCha20Poly1305::new(wrap_key)
+            .encrypt(&wrap_nonce, payload_key.as_slice())
+            .map_err(|e| LedgerError::CryptoError(e.to_string()))?;
+
+        wrapped_keys.push(WrappedKey {
+            recipient: recipient.clone(),
+            kem_ciphertext: kem_ciphertext.as_bytes().to_vec(),
+            wrap_nonce: wrap_nonce.to_vec(),
+            wrapped_key,
+        });
+    }
+
+    Ok((ciphertext, EncryptedPayload { nonce: payload_nonce.to_vec(), wrapped_keys }))
+}
+
+/// Decrypts `ciphertext`/`payload` using a recipient's Kyber secret key,
+/// trying each wrapped key until one unwraps successfully.
+pub(crate) fn decrypt(ciphertext: &[u8], payload: &EncryptedPayload, secret_key: &[u8]) -> Result<Vec<u8>> {
+    let recipient_secret_key = kyber1024::SecretKey::from_bytes(secret_key)
+        .map_err(|e| LedgerError::CryptoError(e.to_string()))?;
+    let payload_nonce = nonce_from_slice(&payload.nonce)?;
+
+    for wrapped in &payload.wrapped_keys {
+        let kem_ciphertext = match kyber1024::Ciphertext::from_bytes(&wrapped.kem_ciphertext) {
+            Ok(kem_ciphertext) => kem_ciphertext,
+            Err(_) => continue,
+        };
+        let wrap_nonce = match nonce_from_slice(&wrapped.wrap_nonce) {
+            Ok(wrap_nonce) => wrap_nonce,
+            Err(_) => continue,
+        };
+        let shared_secret = kyber1024::decapsulate(&kem_ciphertext, &recipient_secret_key);
+
+        let wrap_key_bytes = Sha3_256::digest(shared_secret.as_bytes());
+        let wrap_key = Key::from_slice(&wrap_key_bytes);
+        let payload_key_bytes = match ChaCha20Poly1305::new(wrap_key).decrypt(&wrap_nonce, wrapped.wrapped_key.as_slice()) {
+            Ok(payload_key_bytes) => payload_key_bytes,
+            Err(_) => continue,
+        };
+
+        let payload_key = Key::from_slice(&payload_key_bytes);
+        if let Ok(plaintext) = ChaCha20Poly1305::new(payload_key).decrypt(&payload_nonce, ciphertext) {
+            return Ok(plaintext);
+        }
+    }
+
+    Err(LedgerError::CryptoError("unable to decrypt payload with the given secret key".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt_round_trip() -> Result<()> {
+        let (recipient_pk, recipient_sk) = kyber1024::keypair();
+
+        let (ciphertext, payload) = encrypt(b"confidential data", &[recipient_pk.as_bytes().to_vec()])?;
+        assert_ne!(ciphertext, b"confidential data");
+
+        let plaintext = decrypt(&ciphertext, &payload, recipient_sk.as_bytes())?;
+        assert_eq!(plaintext, b"confidential data");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decrypt_with_wrong_key_fails() -> Result<()> {
+        let (recipient_pk, _) = kyber1024::keypair();
+        let (_, other_sk) = kyber1024::keypair();
+
+        let (ciphertext, payload) = encrypt(b"confidential data", &[recipient_pk.as_bytes().to_vec()])?;
+
+        assert!(decrypt(&ciphertext, &payload, other_sk.as_bytes()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encrypt_for_multiple_recipients() -> Result<()> {
+        let (pk1, sk1) = kyber1024::keypair();
+        let (pk2, sk2) = kyber1024::keypair();
+
+        let (ciphertext, payload) = encrypt(
+            b"shared secret",
+            &[pk1.as_bytes().to_vec(), pk2.as_bytes().to_vec()],
+        )?;
+
+        assert_eq!(decrypt(&ciphertext, &payload, sk1.as_bytes())?, b"shared secret");
+        assert_eq!(decrypt(&ciphertext, &payload, sk2.as_bytes())?, b"shared secret");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decrypt_with_truncated_wrap_nonce_errors_cleanly() -> Result<()> {
+        let (recipient_pk, recipient_sk) = kyber1024::keypair();
+
+        let (ciphertext, mut payload) = encrypt(b"confidential data", &[recipient_pk.as_bytes().to_vec()])?;
+        payload.wrapped_keys[0].wrap_nonce.truncate(3);
+
+        assert!(decrypt(&ciphertext, &payload, recipient_sk.as_bytes()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decrypt_with_truncated_payload_nonce_errors_cleanly() -> Result<()> {
+        let (recipient_pk, recipient_sk) = kyber1024::keypair();
+
+        let (ciphertext, mut payload) = encrypt(b"confidential data", &[recipient_pk.as_bytes().to_vec()])?;
+        payload.nonce.truncate(3);
+
+        assert!(decrypt(&ciphertext, &payload, recipient_sk.as_bytes()).is_err());
+
+        Ok(())
+    }
+}
@@ -37,13 +37,21 @@
 //! hardware-based security features for transaction validation, enabling instant finality
 //! without fees or network latency.
 
+pub mod accumulator;
 pub mod chain;
+mod confidential;
 pub mod error;
+pub mod hardware;
 pub mod types;
 
+pub use accumulator::{InclusionProof, MerkleAccumulator};
 pub use chain::Chain;
 pub use error::{Result, LedgerError};
-pub use types::{Transaction, HardwareAttestation};
+pub use hardware::{HardwareSecurity, SimulatedHardwareSecurity};
+pub use types::{
+    Transaction, HardwareAttestation, TransactionAuthenticator, TransactionPublicKey,
+    MultiQuantumPublicKey, MultiSignature, EncryptedPayload, WrappedKey, ChainExport,
+};
 
 // Version information
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
\ No newline at end of file
@@ -85,7 +85,10 @@ fn list_transactions(chain: &Chain) {
     for tx in transactions {
         println!("ID: {}", tx.id);
         println!("  Timestamp: {}", tx.timestamp);
-        println!("  Data: {}", String::from_utf8_lossy(&tx.data));
+        match &tx.encrypted_payload {
+            Some(payload) => println!("  Data: [encrypted for {} recipient(s)]", payload.wrapped_keys.len()),
+            None => println!("  Data: {}", String::from_utf8_lossy(&tx.data)),
+        }
         if let Some(prev) = tx.previous_transaction {
             println!("  Previous Transaction: {}", prev);
         }
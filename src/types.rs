@@ -11,12 +11,97 @@ pub struct Transaction {
     pub timestamp: DateTime<Utc>,
     /// Actual transaction data
     pub data: Vec<u8>,
-    /// Quantum-resistant signature
-    pub signature: Vec<u8>,
+    /// Quantum-resistant authentication: a single signature or a threshold
+    /// multi-signature, depending on how the chain is configured
+    pub authenticator: TransactionAuthenticator,
     /// Reference to the previous transaction
     pub previous_transaction: Option<Uuid>,
     /// Hardware security attestation data
     pub hardware_attestation: Option<HardwareAttestation>,
+    /// Present when `data` is an AEAD ciphertext rather than plaintext: the
+    /// per-recipient wrapped keys needed to decrypt it
+    pub encrypted_payload: Option<EncryptedPayload>,
+}
+
+/// How a transaction was authenticated.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum TransactionAuthenticator {
+    /// A single Dilithium signature over the transaction's signable digest.
+    Single(Vec<u8>),
+    /// A k-of-n threshold Dilithium multi-signature.
+    MultiQuantum(MultiSignature),
+}
+
+/// A k-of-n threshold Dilithium multi-signature: each entry is the index of
+/// the co-signer's public key in the chain's `MultiQuantumPublicKey`,
+/// paired with the signature it produced over the signable digest.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MultiSignature {
+    /// `(key index, signature bytes)` pairs, one per co-signer.
+    pub signatures: Vec<(u8, Vec<u8>)>,
+}
+
+/// Public key material backing a transaction's authenticator.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum TransactionPublicKey {
+    /// A single Dilithium public key.
+    Single(Vec<u8>),
+    /// A k-of-n threshold Dilithium public key set.
+    MultiQuantum(MultiQuantumPublicKey),
+}
+
+/// Public key material for a k-of-n threshold Dilithium authenticator.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MultiQuantumPublicKey {
+    /// Public key of every potential co-signer, indexed by position.
+    pub keys: Vec<Vec<u8>>,
+    /// Minimum number of distinct valid signatures required.
+    pub threshold: u8,
+}
+
+/// A confidential transaction payload: an AEAD ciphertext (stored in
+/// `Transaction::data`) plus one wrapped copy of its symmetric key per
+/// recipient.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EncryptedPayload {
+    /// AEAD nonce used to encrypt the payload itself.
+    pub nonce: Vec<u8>,
+    /// One wrapped copy of the payload key per recipient.
+    pub wrapped_keys: Vec<WrappedKey>,
+}
+
+/// A recipient's wrapped copy of a transaction's symmetric payload key.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WrappedKey {
+    /// The recipient's KEM public key, used to identify which wrapped key
+    /// belongs to which recipient.
+    pub recipient: Vec<u8>,
+    /// KEM ciphertext encapsulating the secret used to wrap the payload key.
+    pub kem_ciphertext: Vec<u8>,
+    /// AEAD nonce used to wrap the payload key with the KEM shared secret.
+    pub wrap_nonce: Vec<u8>,
+    /// The payload's symmetric key, AEAD-wrapped under the KEM shared secret.
+    pub wrapped_key: Vec<u8>,
+}
+
+/// Serializable snapshot of a chain, produced by `Chain::export`, that a
+/// verifier can use to independently re-verify it end to end via
+/// `Chain::import` - it carries the public key and every transaction, but
+/// never the secret signing key(s), so an importer can verify but not sign.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChainExport {
+    /// Unique identifier of the exported chain
+    pub id: Uuid,
+    /// Human-readable name of the exported chain
+    pub name: String,
+    /// Creation timestamp of the exported chain
+    pub created_at: DateTime<Utc>,
+    /// Public key material needed to verify every transaction's authenticator
+    pub public_key: TransactionPublicKey,
+    /// Every transaction in the chain
+    pub transactions: Vec<Transaction>,
+    /// Merkle accumulator root at the time of export, if any transactions exist
+    pub root: Option<[u8; 32]>,
 }
 
 /// Hardware security attestation information